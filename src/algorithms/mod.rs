@@ -0,0 +1,17 @@
+//! Internal numerical algorithms shared by the public `Uint` methods.
+//!
+//! Everything in this module operates on plain `&[u64]` limb slices rather
+//! than `Uint<BITS, LIMBS>` directly, since several algorithms here (CIOS
+//! reduction, schoolbook multiplication) are easiest to get right as
+//! const-generic-free loops over a runtime-known limb count.
+
+pub mod montgomery;
+pub mod reciprocal;
+
+/// Computes `a * b` as a `(low, high)` double-word without overflow.
+#[inline]
+#[must_use]
+pub(crate) const fn mul_wide(a: u64, b: u64) -> (u64, u64) {
+    let product = (a as u128) * (b as u128);
+    (product as u64, (product >> 64) as u64)
+}
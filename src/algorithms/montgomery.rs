@@ -0,0 +1,111 @@
+//! CIOS Montgomery multiplication, parameterized over a runtime limb count.
+//!
+//! This backs [`crate::modular::MontgomeryParams`] and [`crate::modular::Residue`];
+//! see those for the public-facing API.
+
+use super::mul_wide;
+use alloc::{vec, vec::Vec};
+
+/// Computes `-m^{-1} mod 2^64` for odd `m` via Newton's method.
+///
+/// Any odd `m` is its own inverse mod 8 (3 correct bits); each iteration of
+/// `x <- x * (2 - m * x)` doubles the number of correct bits, so five
+/// iterations are enough to reach full 64-bit precision.
+#[must_use]
+pub(crate) const fn neg_mod_inv(m: u64) -> u64 {
+    let mut inv = m;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Schoolbook `a * b`, returning `2 * a.len()` limbs, least-significant first.
+///
+/// `a` and `b` must have the same length.
+#[must_use]
+pub(crate) fn mul_full(a: &[u64], b: &[u64]) -> Vec<u64> {
+    debug_assert_eq!(a.len(), b.len());
+    let n = a.len();
+    let mut t = vec![0u64; 2 * n];
+    for i in 0..n {
+        let mut carry = 0u64;
+        for j in 0..n {
+            let (lo, hi) = mul_wide(a[i], b[j]);
+            let (sum1, c1) = lo.overflowing_add(t[i + j]);
+            let (sum2, c2) = sum1.overflowing_add(carry);
+            t[i + j] = sum2;
+            carry = hi.wrapping_add(u64::from(c1)).wrapping_add(u64::from(c2));
+        }
+        let mut k = i + n;
+        while carry != 0 {
+            let (sum, c) = t[k].overflowing_add(carry);
+            t[k] = sum;
+            carry = u64::from(c);
+            k += 1;
+        }
+    }
+    t
+}
+
+/// CIOS Montgomery reduction of a `2 * m.len()`-limb product.
+///
+/// `t` is consumed and must hold exactly `2 * m.len()` limbs (as produced by
+/// [`mul_full`]). Returns `m.len()` limbs in `[0, m)`; the caller is not
+/// responsible for any further reduction since this performs the final
+/// conditional subtraction itself.
+#[must_use]
+pub(crate) fn redc(mut t: Vec<u64>, m: &[u64], n0: u64) -> Vec<u64> {
+    let n = m.len();
+    debug_assert_eq!(t.len(), 2 * n);
+    t.push(0);
+    for i in 0..n {
+        let k = t[i].wrapping_mul(n0);
+        let mut carry = 0u64;
+        for j in 0..n {
+            let (lo, hi) = mul_wide(k, m[j]);
+            let (sum1, c1) = lo.overflowing_add(t[i + j]);
+            let (sum2, c2) = sum1.overflowing_add(carry);
+            t[i + j] = sum2;
+            carry = hi.wrapping_add(u64::from(c1)).wrapping_add(u64::from(c2));
+        }
+        let mut k_idx = i + n;
+        while carry != 0 {
+            let (sum, c) = t[k_idx].overflowing_add(carry);
+            t[k_idx] = sum;
+            carry = u64::from(c);
+            k_idx += 1;
+        }
+    }
+    // The reduced value lives in t[n..=2n] and is < 2m; one conditional
+    // subtraction below restores the canonical [0, m) representative.
+    let mut result: Vec<u64> = t[n..=2 * n - 1].to_vec();
+    let top = t[2 * n];
+
+    let mut borrow = false;
+    let mut sub = vec![0u64; n];
+    for j in 0..n {
+        let (d1, b1) = result[j].overflowing_sub(m[j]);
+        let (d2, b2) = d1.overflowing_sub(u64::from(borrow));
+        sub[j] = d2;
+        borrow = b1 || b2;
+    }
+    // `top` absorbs any borrow out of the top limb; if nothing borrowed past
+    // it (and the extra limb itself is nonzero, or the plain subtraction
+    // didn't borrow) the subtracted form is the correct, reduced result.
+    if top > 0 || !borrow {
+        result = sub;
+    }
+    result
+}
+
+/// Montgomery-multiplies `a * b` modulo `m` with reduction constant `n0`.
+///
+/// `a`, `b`, and `m` must all have the same length, and `a`, `b` must already
+/// be in Montgomery form (i.e. `< m`).
+#[must_use]
+pub(crate) fn mul_redc(a: &[u64], b: &[u64], m: &[u64], n0: u64) -> Vec<u64> {
+    redc(mul_full(a, b), m, n0)
+}
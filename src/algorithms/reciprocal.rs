@@ -0,0 +1,228 @@
+//! Barrett-style reciprocal division, parameterized over a runtime limb count.
+//!
+//! This backs [`crate::Reciprocal`]; see there for the public-facing API.
+
+use super::mul_wide;
+use alloc::{vec, vec::Vec};
+
+/// `a >= b`, treating both as little-endian limb vectors of possibly
+/// different lengths (the shorter one is implicitly zero-extended).
+fn ge(a: &[u64], b: &[u64]) -> bool {
+    let len = a.len().max(b.len());
+    for i in (0..len).rev() {
+        let av = a.get(i).copied().unwrap_or(0);
+        let bv = b.get(i).copied().unwrap_or(0);
+        if av != bv {
+            return av > bv;
+        }
+    }
+    true
+}
+
+/// `a -= b` in place, zero-extending `b` if it is shorter than `a`. Returns
+/// the final borrow (should be `false` whenever the caller already checked
+/// `ge(a, b)`).
+fn sub_assign(a: &mut [u64], b: &[u64]) -> bool {
+    let mut borrow = false;
+    for i in 0..a.len() {
+        let bv = b.get(i).copied().unwrap_or(0);
+        let (d1, b1) = a[i].overflowing_sub(bv);
+        let (d2, b2) = d1.overflowing_sub(u64::from(borrow));
+        a[i] = d2;
+        borrow = b1 || b2;
+    }
+    borrow
+}
+
+/// Schoolbook `a * b` for slices of arbitrary (possibly unequal) length,
+/// returning `a.len() + b.len()` limbs.
+pub(crate) fn mul_general(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut t = vec![0u64; a.len() + b.len()];
+    for (i, &ai) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &bj) in b.iter().enumerate() {
+            let (lo, hi) = mul_wide(ai, bj);
+            let (sum1, c1) = lo.overflowing_add(t[i + j]);
+            let (sum2, c2) = sum1.overflowing_add(carry);
+            t[i + j] = sum2;
+            carry = hi.wrapping_add(u64::from(c1)).wrapping_add(u64::from(c2));
+        }
+        let mut k = i + b.len();
+        while carry != 0 {
+            let (sum, c) = t[k].overflowing_add(carry);
+            t[k] = sum;
+            carry = u64::from(c);
+            k += 1;
+        }
+    }
+    t
+}
+
+/// The number of limbs of `d` up to and including its highest nonzero limb
+/// (i.e. ignoring high all-zero limbs of a value stored in a wider type).
+fn significant_len(d: &[u64]) -> usize {
+    for i in (0..d.len()).rev() {
+        if d[i] != 0 {
+            return i + 1;
+        }
+    }
+    1
+}
+
+/// `value << bits` for `bits < 64`, returned as `value.len() + 1` limbs.
+fn shl_small(value: &[u64], bits: u32) -> Vec<u64> {
+    let mut out = vec![0u64; value.len() + 1];
+    if bits == 0 {
+        out[..value.len()].copy_from_slice(value);
+        return out;
+    }
+    let mut carry = 0u64;
+    for (i, &limb) in value.iter().enumerate() {
+        out[i] = (limb << bits) | carry;
+        carry = limb >> (64 - bits);
+    }
+    out[value.len()] = carry;
+    out
+}
+
+/// `value >> bits` for `bits < 64`, returned with the same limb count as
+/// `value` (the vacated high bits become zero).
+fn shr_small(value: &[u64], bits: u32) -> Vec<u64> {
+    let mut out = vec![0u64; value.len()];
+    if bits == 0 {
+        out.copy_from_slice(value);
+        return out;
+    }
+    let mut carry = 0u64;
+    for i in (0..value.len()).rev() {
+        out[i] = (value[i] >> bits) | carry;
+        carry = value[i] << (64 - bits);
+    }
+    out
+}
+
+/// Computes `(floor(numerator / divisor), numerator % divisor)` via
+/// schoolbook bit-serial long division. This is exact for divisors and
+/// dividends of any relative size, which makes it the one-time basis for
+/// deriving a reciprocal, and the exact fallback when a single Barrett
+/// estimate isn't provably bounded (see [`estimate_and_correct`]).
+fn div_rem_bitwise(numerator: &[u64], divisor: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let total_bits = numerator.len() * 64;
+    let mut quotient = vec![0u64; numerator.len()];
+    let mut remainder = vec![0u64; numerator.len()];
+    for bit in (0..total_bits).rev() {
+        let mut carry = 0u64;
+        for limb in remainder.iter_mut() {
+            let new_carry = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = new_carry;
+        }
+        let limb_idx = bit / 64;
+        let bit_idx = bit % 64;
+        remainder[0] |= (numerator[limb_idx] >> bit_idx) & 1;
+        if ge(&remainder, divisor) {
+            sub_assign(&mut remainder, divisor);
+            quotient[limb_idx] |= 1u64 << bit_idx;
+        }
+    }
+    (quotient, remainder)
+}
+
+/// Derives a Barrett reciprocal for divisor `d`.
+///
+/// Returns `(mu, m, shift)`, where `m` is the number of significant limbs of
+/// `d` (its high all-zero limbs, if `d` is stored in a wider type, don't
+/// count), `shift` is `d`'s leading-zero count within its top significant
+/// limb (so that `d << shift` is normalized, i.e. has its top bit set), and
+/// `mu = floor(b^(2m) / (d << shift))`, which fits in `m + 1` limbs because
+/// the normalized divisor is `>= b^m / 2`.
+///
+/// This is a one-time setup cost, proportional to the square of `m`; it is
+/// meant to be amortized across many subsequent [`estimate_and_correct`]
+/// calls under the same divisor.
+pub(crate) fn compute_reciprocal(d: &[u64]) -> (Vec<u64>, usize, u32) {
+    let m = significant_len(d);
+    let shift = d[m - 1].leading_zeros();
+    let d_norm: Vec<u64> = shl_small(&d[..m], shift)[..m].to_vec();
+
+    let mut numerator = vec![0u64; 2 * m + 1];
+    numerator[2 * m] = 1; // b^(2m)
+    let (mu, _) = div_rem_bitwise(&numerator, &d_norm);
+    (mu[..=m].to_vec(), m, shift)
+}
+
+/// Computes `(floor(x / d), x mod d)` given the reciprocal data returned by
+/// [`compute_reciprocal`] (`mu`, the significant limb count `m` of `d`, and
+/// its normalization shift).
+///
+/// When `x` has *fewer than* `2 * m` limbs, HAC Algorithm 14.42's
+/// precondition holds, so a single Barrett estimate (multiply by the
+/// precomputed `mu`, subtract `q * d`, then at most two `q += 1` / `r -= d`
+/// corrections) is provably exact. At `n == 2 * m`, `x_norm = x << shift`
+/// can reach up to `~b^(2m) * 2^63`, which pushes the estimate error past
+/// what two corrections can fix, so that case — along with `n > 2 * m` —
+/// falls back to exact long division.
+pub(crate) fn estimate_and_correct(
+    x: &[u64],
+    d: &[u64],
+    mu: &[u64],
+    m: usize,
+    shift: u32,
+) -> (Vec<u64>, Vec<u64>) {
+    let n = x.len();
+
+    if n >= 2 * m {
+        return div_rem_bitwise(x, d);
+    }
+
+    let d_norm: Vec<u64> = shl_small(&d[..m], shift)[..m].to_vec();
+    let x_norm: Vec<u64> = shl_small(x, shift); // n + 1 limbs
+
+    // q_est = floor(x_norm * mu / b^(2m))
+    let product = mul_general(&x_norm, mu);
+    let mut q = product[(2 * m)..].to_vec();
+
+    let qd = mul_general(&q, &d_norm);
+    let width = x_norm.len().max(qd.len()) + 1;
+    let mut r = x_norm;
+    r.resize(width, 0);
+    let mut qd_ext = qd;
+    qd_ext.resize(width, 0);
+    if sub_assign(&mut r, &qd_ext) {
+        // The estimate overshot by one: add back d_norm.
+        let mut carry = false;
+        for i in 0..r.len() {
+            let dv = d_norm.get(i).copied().unwrap_or(0);
+            let (s1, c1) = r[i].overflowing_add(dv);
+            let (s2, c2) = s1.overflowing_add(u64::from(carry));
+            r[i] = s2;
+            carry = c1 || c2;
+        }
+        sub_assign(&mut q, &[1]);
+    }
+
+    for _ in 0..2 {
+        if ge(&r, &d_norm) {
+            sub_assign(&mut r, &d_norm);
+            let mut carry = true;
+            for limb in q.iter_mut() {
+                let (s, c) = limb.overflowing_add(u64::from(carry));
+                *limb = s;
+                carry = c;
+                if !carry {
+                    break;
+                }
+            }
+        }
+    }
+
+    // The true quotient is `< b^n` and the true remainder `< d <= d_norm`,
+    // so both fit back into `n` / `m` limbs once corrected.
+    q.resize(n, 0);
+    r.truncate(m);
+    let r = shr_small(&r, shift);
+    let mut r_full = vec![0u64; n];
+    r_full[..r.len()].copy_from_slice(&r);
+
+    (q, r_full)
+}
@@ -0,0 +1,114 @@
+//! Chinese Remainder Theorem combination via Garner's method.
+
+use crate::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Combines `self mod modulus_self` and `other mod modulus_other` into
+    /// the unique representative `x` of the system
+    ///
+    /// ```text
+    /// x ≡ self  (mod modulus_self)
+    /// x ≡ other (mod modulus_other)
+    /// ```
+    ///
+    /// via Garner's method, returning `(x, lcm)` where `lcm` is
+    /// `lcm(modulus_self, modulus_other)` and `x` is the representative in
+    /// `[0, lcm)`.
+    ///
+    /// Returns `None` if the system is inconsistent (`self` and `other`
+    /// disagree modulo `gcd(modulus_self, modulus_other)`), or if `lcm`
+    /// would overflow `Uint<BITS, LIMBS>`.
+    #[must_use]
+    pub fn crt(self, modulus_self: Self, other: Self, modulus_other: Self) -> Option<(Self, Self)> {
+        let g = modulus_self.gcd(modulus_other);
+        if self % g != other % g {
+            return None;
+        }
+
+        let m1 = modulus_self / g;
+        let m2 = modulus_other / g;
+        let lcm = m1.checked_mul(modulus_other)?;
+
+        // (other - self) / g, computed without signed arithmetic: `self_r` is
+        // reduced mod modulus_other, so whenever `other < self_r` both are,
+        // and a single conditional add-back avoids underflow.
+        let self_r = self % modulus_other;
+        let diff_full = if other >= self_r {
+            other - self_r
+        } else {
+            modulus_other - (self_r - other)
+        };
+        let diff = diff_full / g;
+
+        let inv = m1.inv_mod(m2)?;
+        let k = diff.mul_mod(inv, m2);
+
+        // Garner's reconstruction adds a multiple of the *full* modulus,
+        // not `modulus_self / g`, even though `k` itself was computed mod
+        // `modulus_other / g`.
+        let offset = modulus_self.mul_mod(k, lcm);
+        let (sum, carried) = (self % lcm).overflowing_add(offset);
+        let x = if carried {
+            // `self + offset` overflowed the fixed width; since both are
+            // `< lcm`, the true sum is `< 2 * lcm`, so subtracting `lcm`
+            // once (via its two's-complement negation) is enough.
+            sum.wrapping_add(Self::ZERO.wrapping_sub(lcm))
+        } else {
+            let (reduced, borrowed) = sum.overflowing_sub(lcm);
+            if borrowed {
+                sum
+            } else {
+                reduced
+            }
+        };
+
+        Some((x, lcm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::U64;
+    use proptest::prelude::*;
+
+    fn gcd_u64(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd_u64(b, a % b)
+        }
+    }
+
+    #[test]
+    fn uses_full_modulus_self_not_reduced() {
+        // self=1 (mod 4), other=3 (mod 6) -> x=9 (mod lcm=12).
+        let (x, lcm) = U64::from(1u64)
+            .crt(U64::from(4u64), U64::from(3u64), U64::from(6u64))
+            .unwrap();
+        assert_eq!(x, U64::from(9u64));
+        assert_eq!(lcm, U64::from(12u64));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(x in 0u64..64, m1 in 1u64..32, y in 0u64..64, m2 in 1u64..32) {
+            let x = x % m1;
+            let y = y % m2;
+            let result = U64::from(x).crt(U64::from(m1), U64::from(y), U64::from(m2));
+
+            let g = gcd_u64(m1, m2);
+            if x % g != y % g {
+                prop_assert!(result.is_none());
+            } else {
+                let (combined, lcm) = result.unwrap();
+                let expected_lcm = m1 / g * m2;
+                prop_assert_eq!(lcm, U64::from(expected_lcm));
+                let combined = combined.as_limbs()[0];
+                prop_assert!(combined < expected_lcm);
+                prop_assert_eq!(combined % m1, x);
+                prop_assert_eq!(combined % m2, y);
+            }
+        }
+    }
+}
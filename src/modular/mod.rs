@@ -0,0 +1,11 @@
+//! Modular arithmetic helpers that amortize per-modulus setup.
+//!
+//! [`MontgomeryParams`] and [`Residue`] give callers that perform many
+//! operations under one fixed modulus (e.g. repeated `pow_mod_redc` calls, as
+//! in the `modexp_amortized` benchmark) a way to pay the Montgomery setup
+//! cost once instead of on every call.
+
+mod multi_exp;
+mod residue;
+
+pub use residue::{MontgomeryParams, Residue};
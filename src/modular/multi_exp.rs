@@ -0,0 +1,103 @@
+use crate::modular::{MontgomeryParams, Residue};
+use crate::Uint;
+use alloc::vec::Vec;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes `product(base_i ^ exp_i) mod modulus` for all `(base, exp)`
+    /// pairs in `bases_exps`, via Straus's interleaved-windowing algorithm.
+    ///
+    /// This is far cheaper than computing each `pow_mod` independently and
+    /// multiplying the results, since the squarings of the shared
+    /// accumulator are done once per exponent bit position rather than once
+    /// per base. Uses a window width of one bit per base: all `2^k` subset
+    /// products of the `k` bases are precomputed once (including the
+    /// empty-subset entry, the Montgomery representative of one), then each
+    /// bit position across all exponents selects one table entry to fold in.
+    ///
+    /// A zero exponent simply never contributes its base to the selected
+    /// table entry, so it naturally contributes a factor of one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is even, or if `bases_exps` is empty.
+    #[must_use]
+    pub fn multi_pow_mod(bases_exps: &[(Self, Self)], modulus: Self) -> Self {
+        assert!(!bases_exps.is_empty(), "multi_pow_mod requires at least one base");
+        let k = bases_exps.len();
+
+        let params = MontgomeryParams::new(modulus);
+        let residues: Vec<_> = bases_exps
+            .iter()
+            .map(|(base, _)| Residue::new(*base, &params))
+            .collect();
+
+        // table[mask] = product of residues[i] for every set bit i of mask.
+        let mut table: Vec<Residue<'_, BITS, LIMBS>> = Vec::with_capacity(1 << k);
+        table.push(Residue::one(&params));
+        for mask in 1..(1usize << k) {
+            let lowest_bit = mask.trailing_zeros() as usize;
+            let rest = mask & (mask - 1);
+            table.push(table[rest].mul(&residues[lowest_bit]));
+        }
+
+        let max_bits = bases_exps
+            .iter()
+            .map(|(_, exp)| exp.bit_len())
+            .max()
+            .unwrap_or(0);
+
+        let mut accumulator = Residue::one(&params);
+        for bit in (0..max_bits).rev() {
+            accumulator = accumulator.square();
+            let mut mask = 0usize;
+            for (i, (_, exp)) in bases_exps.iter().enumerate() {
+                if exp.bit(bit) {
+                    mask |= 1 << i;
+                }
+            }
+            if mask != 0 {
+                accumulator = accumulator.mul(&table[mask]);
+            }
+        }
+
+        accumulator.retrieve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::U64;
+    use proptest::prelude::*;
+
+    #[test]
+    fn single_base_matches_pow_mod() {
+        let modulus = U64::from(97u64);
+        let base = U64::from(5u64);
+        let exp = U64::from(13u64);
+        let result = Uint::multi_pow_mod(&[(base, exp)], modulus);
+        assert_eq!(result, base.pow_mod(exp, modulus));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_product_of_individual_pow_mod(
+            bases_exps in prop::collection::vec((0u64..1000, 0u64..1000), 1..5),
+            modulus in 1u64..1000,
+        ) {
+            let modulus = U64::from(modulus | 1);
+            let pairs: Vec<_> = bases_exps
+                .iter()
+                .map(|&(b, e)| (U64::from(b), U64::from(e)))
+                .collect();
+
+            let expected = pairs
+                .iter()
+                .fold(U64::from(1u64), |acc, &(base, exp)| {
+                    acc.mul_mod(base.pow_mod(exp, modulus), modulus)
+                });
+
+            prop_assert_eq!(Uint::multi_pow_mod(&pairs, modulus), expected);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::algorithms::montgomery::{mul_redc, neg_mod_inv};
+use crate::Uint;
+
+/// Precomputed Montgomery parameters for a fixed odd modulus.
+///
+/// Deriving `n0` and `r2` costs time proportional to the modulus size, so
+/// sharing one `MontgomeryParams` across many [`Residue`] values avoids
+/// repeating that setup on every operation, unlike [`Uint::pow_mod_redc`]
+/// which leaves deriving `n0` to each of its callers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MontgomeryParams<const BITS: usize, const LIMBS: usize> {
+    modulus: Uint<BITS, LIMBS>,
+    n0: u64,
+    r2: Uint<BITS, LIMBS>,
+}
+
+impl<const BITS: usize, const LIMBS: usize> MontgomeryParams<BITS, LIMBS> {
+    /// Derives Montgomery parameters for `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero or even; Montgomery reduction requires an
+    /// odd modulus.
+    #[must_use]
+    pub fn new(modulus: Uint<BITS, LIMBS>) -> Self {
+        assert!(!modulus.is_zero(), "modulus must be nonzero");
+        assert!(modulus.bit(0), "modulus must be odd");
+        let n0 = neg_mod_inv(modulus.as_limbs()[0]);
+        // r2 = (2^(64*LIMBS))^2 mod m, computed by reusing `pow_mod` rather
+        // than re-deriving a doubling-based reduction here.
+        let r_squared_exponent = Uint::<BITS, LIMBS>::from(128 * LIMBS as u64);
+        let r2 = Uint::<BITS, LIMBS>::from(2u64).pow_mod(r_squared_exponent, modulus);
+        Self { modulus, n0, r2 }
+    }
+
+    /// The modulus these parameters were derived for.
+    #[must_use]
+    pub const fn modulus(&self) -> Uint<BITS, LIMBS> {
+        self.modulus
+    }
+}
+
+/// A value held in Montgomery form under a fixed [`MontgomeryParams`].
+///
+/// Construct with [`Residue::new`] and map back to a plain `Uint` with
+/// [`Residue::retrieve`]. `add`, `sub`, `mul`, `square`, and `pow` all stay in
+/// Montgomery form, so a chain of operations pays the REDC cost of each
+/// multiplication but no additional mapping in or out.
+#[derive(Clone, Copy, Debug)]
+pub struct Residue<'a, const BITS: usize, const LIMBS: usize> {
+    value: Uint<BITS, LIMBS>,
+    params: &'a MontgomeryParams<BITS, LIMBS>,
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize> Residue<'a, BITS, LIMBS> {
+    /// Maps `value` into Montgomery form under `params`.
+    #[must_use]
+    pub fn new(value: Uint<BITS, LIMBS>, params: &'a MontgomeryParams<BITS, LIMBS>) -> Self {
+        let value = value % params.modulus;
+        let limbs = mul_redc(
+            value.as_limbs(),
+            params.r2.as_limbs(),
+            params.modulus.as_limbs(),
+            params.n0,
+        );
+        Self {
+            value: Uint::from_limbs_slice(&limbs),
+            params,
+        }
+    }
+
+    /// The Montgomery representative `1 * R mod m`.
+    #[must_use]
+    pub fn one(params: &'a MontgomeryParams<BITS, LIMBS>) -> Self {
+        Self::new(Uint::from(1u64), params)
+    }
+
+    /// Maps this value back out of Montgomery form.
+    #[must_use]
+    pub fn retrieve(&self) -> Uint<BITS, LIMBS> {
+        let limbs = mul_redc(
+            self.value.as_limbs(),
+            Uint::<BITS, LIMBS>::from(1u64).as_limbs(),
+            self.params.modulus.as_limbs(),
+            self.params.n0,
+        );
+        Uint::from_limbs_slice(&limbs)
+    }
+
+    fn reduced_add(&self, rhs: &Self, sub: bool) -> Uint<BITS, LIMBS> {
+        let (raw, carried) = if sub {
+            self.value.overflowing_sub(rhs.value)
+        } else {
+            self.value.overflowing_add(rhs.value)
+        };
+        let modulus = self.params.modulus;
+        if sub {
+            if carried {
+                raw.wrapping_add(modulus)
+            } else {
+                raw
+            }
+        } else {
+            let (reduced, borrow) = raw.overflowing_sub(modulus);
+            if carried || !borrow {
+                reduced
+            } else {
+                raw
+            }
+        }
+    }
+
+    /// Adds two residues under the same modulus.
+    #[must_use]
+    pub fn add(&self, rhs: &Self) -> Self {
+        Self {
+            value: self.reduced_add(rhs, false),
+            params: self.params,
+        }
+    }
+
+    /// Subtracts `rhs` from `self` under the same modulus.
+    #[must_use]
+    pub fn sub(&self, rhs: &Self) -> Self {
+        Self {
+            value: self.reduced_add(rhs, true),
+            params: self.params,
+        }
+    }
+
+    /// Multiplies two residues, via a single REDC multiply.
+    #[must_use]
+    pub fn mul(&self, rhs: &Self) -> Self {
+        let limbs = mul_redc(
+            self.value.as_limbs(),
+            rhs.value.as_limbs(),
+            self.params.modulus.as_limbs(),
+            self.params.n0,
+        );
+        Self {
+            value: Uint::from_limbs_slice(&limbs),
+            params: self.params,
+        }
+    }
+
+    /// Squares this residue, via a single REDC multiply.
+    #[must_use]
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Raises this residue to `exponent`, via square-and-multiply.
+    #[must_use]
+    pub fn pow(&self, exponent: Uint<BITS, LIMBS>) -> Self {
+        let mut result = Self::one(self.params);
+        let mut base = *self;
+        for i in 0..exponent.bit_len() {
+            if exponent.bit(i) {
+                result = result.mul(&base);
+            }
+            base = base.square();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::U64;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trips_through_montgomery_form(value: u64, modulus in 1u64..u64::MAX) {
+            let modulus_u64 = modulus | 1;
+            let params = MontgomeryParams::new(U64::from(modulus_u64));
+            let residue = Residue::new(U64::from(value), &params);
+            prop_assert_eq!(residue.retrieve().as_limbs()[0], value % modulus_u64);
+        }
+
+        #[test]
+        fn add_sub_mul_match_native_mod_arithmetic(a: u64, b: u64, modulus in 1u64..u64::MAX) {
+            let modulus_u64 = modulus | 1;
+            let params = MontgomeryParams::new(U64::from(modulus_u64));
+            let ra = Residue::new(U64::from(a), &params);
+            let rb = Residue::new(U64::from(b), &params);
+
+            let expected_add = ((u128::from(a) + u128::from(b)) % u128::from(modulus_u64)) as u64;
+            prop_assert_eq!(ra.add(&rb).retrieve().as_limbs()[0], expected_add);
+
+            let expected_mul = (u128::from(a) * u128::from(b) % u128::from(modulus_u64)) as u64;
+            prop_assert_eq!(ra.mul(&rb).retrieve().as_limbs()[0], expected_mul);
+
+            let expected_sub = (i128::from(a % modulus_u64) - i128::from(b % modulus_u64))
+                .rem_euclid(i128::from(modulus_u64)) as u64;
+            prop_assert_eq!(ra.sub(&rb).retrieve().as_limbs()[0], expected_sub);
+        }
+
+        #[test]
+        fn pow_matches_repeated_multiplication(base: u64, exp in 0u32..64, modulus in 1u64..u64::MAX) {
+            let modulus_u64 = modulus | 1;
+            let params = MontgomeryParams::new(U64::from(modulus_u64));
+            let residue = Residue::new(U64::from(base), &params);
+
+            let mut expected = 1u128;
+            let base_mod = u128::from(base % modulus_u64);
+            for _ in 0..exp {
+                expected = expected * base_mod % u128::from(modulus_u64);
+            }
+            let result = residue.pow(U64::from(u64::from(exp))).retrieve();
+            prop_assert_eq!(result.as_limbs()[0], expected as u64);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! A precomputed reciprocal for fast repeated division by a fixed divisor.
+//!
+//! Mirrors the role [`crate::modular::MontgomeryParams`] plays for repeated
+//! multiplication: [`Reciprocal::new`] does the Barrett setup once, and
+//! [`Uint::rem_precomputed`] / [`Uint::div_rem_precomputed`] then reuse it for
+//! every division under that divisor (see the `modexp_amortized` benchmark).
+//! The reciprocal is only used when the dividend has fewer than twice as many
+//! limbs as the divisor has significant limbs; wider dividends are divided by
+//! bit-serial long division.
+
+use crate::algorithms::reciprocal::{compute_reciprocal, estimate_and_correct};
+use crate::Uint;
+use alloc::vec::Vec;
+
+/// A precomputed Barrett reciprocal for a fixed nonzero divisor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reciprocal<const BITS: usize, const LIMBS: usize> {
+    divisor: Uint<BITS, LIMBS>,
+    mu: Vec<u64>,
+    /// Number of significant (non-leading-zero) limbs of `divisor`.
+    significant_limbs: usize,
+    /// `divisor`'s leading-zero count within its top significant limb.
+    shift: u32,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Reciprocal<BITS, LIMBS> {
+    /// Derives a reciprocal for `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[must_use]
+    pub fn new(divisor: Uint<BITS, LIMBS>) -> Self {
+        assert!(!divisor.is_zero(), "divisor must be nonzero");
+        let (mu, significant_limbs, shift) = compute_reciprocal(divisor.as_limbs());
+        Self {
+            divisor,
+            mu,
+            significant_limbs,
+            shift,
+        }
+    }
+
+    /// The divisor this reciprocal was derived for.
+    #[must_use]
+    pub const fn divisor(&self) -> Uint<BITS, LIMBS> {
+        self.divisor
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes `(self / reciprocal.divisor(), self % reciprocal.divisor())`
+    /// using a precomputed [`Reciprocal`].
+    ///
+    /// When the dividend's limb count is less than twice the divisor's
+    /// significant limb count, this is a Barrett estimate with at most a few
+    /// single-step corrections. Otherwise it falls back to bit-serial long
+    /// division and gains nothing from the reciprocal.
+    #[must_use]
+    pub fn div_rem_precomputed(&self, reciprocal: &Reciprocal<BITS, LIMBS>) -> (Self, Self) {
+        let (q, r) = estimate_and_correct(
+            self.as_limbs(),
+            reciprocal.divisor.as_limbs(),
+            &reciprocal.mu,
+            reciprocal.significant_limbs,
+            reciprocal.shift,
+        );
+        (Self::from_limbs_slice(&q), Self::from_limbs_slice(&r))
+    }
+
+    /// Computes `self % reciprocal.divisor()` using a precomputed
+    /// [`Reciprocal`].
+    #[must_use]
+    pub fn rem_precomputed(&self, reciprocal: &Reciprocal<BITS, LIMBS>) -> Self {
+        self.div_rem_precomputed(reciprocal).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{U128, U256, U64};
+    use proptest::prelude::*;
+
+    #[test]
+    fn small_divisor_in_a_wide_type() {
+        // Regression test: a divisor with many leading zero limbs (7 stored
+        // in a 256-bit type) used to make `mu` truncate to garbage.
+        let d = U256::from(7u64);
+        let reciprocal = Reciprocal::new(d);
+        for value in [0u64, 1, 6, 7, 8, 100, u64::MAX] {
+            let x = U256::from(value);
+            let (q, r) = x.div_rem_precomputed(&reciprocal);
+            assert_eq!(q, x / d);
+            assert_eq!(r, x % d);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn matches_native_div_rem_u64(x: u64, d in 1u64..u64::MAX) {
+            let x = U64::from(x);
+            let d = U64::from(d);
+            let reciprocal = Reciprocal::new(d);
+            let (q, r) = x.div_rem_precomputed(&reciprocal);
+            prop_assert_eq!(q, x / d);
+            prop_assert_eq!(r, x % d);
+        }
+
+        #[test]
+        fn matches_native_div_rem_u256_narrow_divisor(x: u64, d in 1u64..u64::MAX) {
+            let x = U256::from(x);
+            let d = U256::from(d);
+            let reciprocal = Reciprocal::new(d);
+            let (q, r) = x.div_rem_precomputed(&reciprocal);
+            prop_assert_eq!(q, x / d);
+            prop_assert_eq!(r, x % d);
+        }
+
+        // `U128` with a divisor this narrow gives `d`'s significant limb
+        // count `m == 1` and `x`'s limb count `n == 2`, i.e. exactly the
+        // `n == 2 * m` band that a single Barrett estimate can't cover.
+        #[test]
+        fn matches_native_div_rem_u128_at_the_n_eq_2m_band(x: u128, d in 1u64..u64::MAX) {
+            let x = U128::from(x);
+            let d = U128::from(d);
+            let reciprocal = Reciprocal::new(d);
+            let (q, r) = x.div_rem_precomputed(&reciprocal);
+            prop_assert_eq!(q, x / d);
+            prop_assert_eq!(r, x % d);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Integer `n`th roots via Newton's method.
+
+use crate::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Computes `x^k`, short-circuiting to `None` as soon as the partial
+    /// product overflows the type or exceeds `bound` — since in that case
+    /// the caller only needs to know that `x^k` exceeds `bound`.
+    fn pow_capped(x: Self, k: u32, bound: Self) -> Option<Self> {
+        let mut acc = Self::from(1u64);
+        for _ in 0..k {
+            let (product, overflow) = acc.overflowing_mul(x);
+            if overflow || product > bound {
+                return None;
+            }
+            acc = product;
+        }
+        Some(acc)
+    }
+
+    /// A seed for the Newton iteration, chosen so it is never smaller than
+    /// the true root: `2^(ceil(bit_len(value) / n))`, saturating to `MAX`
+    /// rather than overflowing the type for small widths (e.g. `U1`).
+    fn nth_root_seed(value: Self, n: u32) -> Self {
+        let bit_len = value.bit_len() as u32;
+        let shift = ((bit_len + n - 1) / n).max(1);
+        if shift as usize >= BITS {
+            Self::MAX
+        } else {
+            Self::from(1u64) << (shift as usize)
+        }
+    }
+
+    /// One step of `x <- ((n-1)*x + value/x^(n-1)) / n`.
+    ///
+    /// If `x^(n-1)` overflows or already exceeds `value`, the quotient term
+    /// is zero: `x` is already at least as large as the true root, so this
+    /// term would only have pulled the next estimate down anyway. `(n-1)*x`
+    /// saturates rather than overflowing for the same reason: it only
+    /// happens when `x` is already far above the true root, so clamping it
+    /// to `MAX` still pushes the next estimate down.
+    fn nth_root_step(x: Self, value: Self, n: u32) -> Self {
+        let quotient = match Self::pow_capped(x, n - 1, value) {
+            Some(p) if !p.is_zero() => value / p,
+            _ => Self::ZERO,
+        };
+        let scaled = Self::from(u64::from(n - 1)).saturating_mul(x);
+        let numerator = scaled.saturating_add(quotient);
+        numerator / Self::from(u64::from(n))
+    }
+
+    /// Computes `floor(self^(1/n))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n == 0`.
+    #[must_use]
+    pub fn nth_root(self, n: u32) -> Self {
+        assert!(n > 0, "nth_root: n must be nonzero");
+        if self.is_zero() || n == 1 {
+            return self;
+        }
+        if n as usize >= BITS {
+            // `self < 2^BITS <= 2^n` and `self >= 1`, so the root is 1.
+            // Handling this up front also keeps every `Self::from(n)` below
+            // in range: `n < BITS` always holds past this point.
+            return Self::from(1u64);
+        }
+
+        let mut x = Self::nth_root_seed(self, n);
+        loop {
+            let next = Self::nth_root_step(x, self, n);
+            if next >= x {
+                break;
+            }
+            x = next;
+        }
+
+        // The loop above only ever stops one step past the true root (it
+        // converges monotonically from above), so at most one correction is
+        // needed to restore `x^n <= self`.
+        if Self::pow_capped(x, n, self).is_none() {
+            x = x - Self::from(1u64);
+        }
+        x
+    }
+
+    /// Computes `floor(self^(1/n))`, returning `None` unless it is exact
+    /// (i.e. `self` is a perfect `n`th power).
+    #[must_use]
+    pub fn checked_nth_root(self, n: u32) -> Option<Self> {
+        let root = self.nth_root(n);
+        match Self::pow_capped(root, n, self) {
+            Some(p) if p == self => Some(root),
+            _ => None,
+        }
+    }
+
+    /// Computes `floor(sqrt(self))`.
+    #[must_use]
+    pub fn isqrt(self) -> Self {
+        self.nth_root(2)
+    }
+
+    /// Computes `floor(sqrt(self))`, returning `None` unless `self` is a
+    /// perfect square.
+    #[must_use]
+    pub fn checked_isqrt(self) -> Option<Self> {
+        self.checked_nth_root(2)
+    }
+
+    /// Computes `floor(cbrt(self))`.
+    #[must_use]
+    pub fn icbrt(self) -> Self {
+        self.nth_root(3)
+    }
+
+    /// Computes `floor(cbrt(self))`, returning `None` unless `self` is a
+    /// perfect cube.
+    #[must_use]
+    pub fn checked_icbrt(self) -> Option<Self> {
+        self.checked_nth_root(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aliases::{U1, U64, U8};
+    use proptest::prelude::*;
+
+    #[test]
+    fn large_n_does_not_overflow_small_width() {
+        // n=300 exceeds what a `u8`-wide value could ever fit into via
+        // `Self::from`; the root must still just be 1 for any nonzero value.
+        for value in [1u8, 2, 255] {
+            assert_eq!(U8::from(value).nth_root(300), U8::from(1u64));
+        }
+    }
+
+    #[test]
+    fn isqrt_one_on_a_single_bit_type() {
+        assert_eq!(U1::from(1u64).isqrt(), U1::from(1u64));
+        assert_eq!(U1::from(0u64).isqrt(), U1::from(0u64));
+    }
+
+    proptest! {
+        #[test]
+        fn nth_root_brackets_the_value(value: u64, n in 1u32..10) {
+            let root = U64::from(value).nth_root(n);
+            let root = root.as_limbs()[0];
+            prop_assert!(root.checked_pow(n).map_or(true, |p| p <= value));
+            prop_assert!((root + 1).checked_pow(n).map_or(true, |p| p > value));
+        }
+
+        #[test]
+        fn checked_nth_root_exact_roundtrip(root in 0u64..1000, n in 2u32..5) {
+            let value = match root.checked_pow(n) {
+                Some(v) => v,
+                None => return Ok(()),
+            };
+            prop_assert_eq!(
+                U64::from(value).checked_nth_root(n),
+                Some(U64::from(root))
+            );
+        }
+    }
+}